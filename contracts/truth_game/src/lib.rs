@@ -1,9 +1,10 @@
 // --- Two Truths and a Lie ---
 #![no_std]
 
-// We need more imports: Map for storage, Vec for lists, BytesN for the hash
+// We need more imports: Map for storage, Vec for lists, BytesN for the hash,
+// and the token module so we can move the wager in and out of escrow.
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
+    contract, contractimpl, contracttype, symbol_short, token,
     Address, Env, String, Map, Vec, BytesN,
 };
 
@@ -17,10 +18,59 @@ pub struct Game {
     pub commit_hash: BytesN<32>, // The SHA-256 hash of the secret data
     pub reveal_time: u64,       // When the game can be revealed
 
-    // These fields start empty and are filled in *after* the reveal
-    pub statements: Vec<String>, // The 3 statements (revealed)
-    pub lie_index: u32,          // The index (0, 1, or 2) of the lie
+    // These fields start empty and are filled in *after* the reveal.
+    // The statement count and number of lies are both caller-chosen: this
+    // supports "two truths and a lie", "three truths and two lies", etc.
+    pub statements: Vec<String>, // The statements (revealed)
+    pub lie_indices: Vec<u32>,   // The (sorted) indices of the lies
     pub revealed: bool,          // Has this game been revealed?
+
+    // Wagering is optional: if `token` is None the game is a free-to-play
+    // commit-reveal and `stake` is ignored.
+    pub token: Option<Address>, // SAC token used for the pot, if any
+    pub stake: i128,            // Amount every participant must deposit
+
+    pub forfeited: bool, // Did the owner miss the reveal window?
+    pub settled: bool,   // Has `settle` already paid out this game's pot?
+}
+
+// A single player's lifetime record, accumulated across every game they
+// touch. This is what `get_leaderboard` ranks players by.
+#[contracttype]
+#[derive(Clone)]
+pub struct PlayerStats {
+    pub games_created: u32,
+    pub guesses_made: u32,
+    pub correct_guesses: u32,
+}
+
+// Contract-wide totals, tracked alongside the per-player stats.
+#[contracttype]
+#[derive(Clone)]
+pub struct GlobalStats {
+    pub games_revealed: u32,
+    pub correct_guesses: u32,
+}
+
+// Whether a tournament is still accepting attached games, or has been
+// scored and closed out.
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum TournamentStatus {
+    Open,
+    Finalized,
+}
+
+// A multi-round competition built on top of the single-game primitives.
+// Revealing each attached game automatically feeds its winners into `scores`.
+#[contracttype]
+#[derive(Clone)]
+pub struct Tournament {
+    pub organizer: Address,
+    pub game_ids: Vec<u32>,
+    pub players: Vec<Address>,
+    pub scores: Map<Address, u32>,
+    pub status: TournamentStatus,
 }
 
 // 'DataKey' defines our storage slots
@@ -29,6 +79,14 @@ pub enum DataKey {
     GameCounter, // A single number: how many games exist
     Games(u32),  // A map storing Games by their ID (e.g., Games(1), Games(2))
     Guesses(u32, Address), // A map storing a user's guess for a specific game
+    Guessers(u32), // The ordered list of addresses that have guessed on a game
+    Pot(u32),      // The total amount (owner stake + guesser stakes) held for a game
+    PlayerStats(Address), // Per-player lifetime stats
+    Players,              // Every address that has ever created or guessed a game
+    GlobalStats,          // Contract-wide totals
+    TournamentCounter,    // A single number: how many tournaments exist
+    Tournaments(u32),     // A map storing Tournaments by their ID
+    GameTournament(u32),  // Which tournament (if any) a game is attached to
 }
 
 #[contract]
@@ -39,29 +97,73 @@ pub struct TruthsGameContract;
 impl TruthsGameContract {
 
     /// Creates a new game by storing a 'commitment hash'.
-    /// The hash is of (statements + lie_index + salt)
+    /// The hash is of (statement count + statements + sorted lie indices + salt)
     /// The user must provide this hash from the frontend.
-    pub fn commit(env: Env, owner: Address, hash: BytesN<32>) -> u32 {
+    ///
+    /// `token`/`stake` are optional: pass `None` and `0` for a free game.
+    /// When a wager is set, the owner's stake is pulled into the contract
+    /// immediately and becomes the seed of the game's pot.
+    ///
+    /// `duration` is the number of seconds the guessing window stays open
+    /// for, starting now; `reveal` is rejected before it elapses and
+    /// `guess`/`forfeit` use it to tell when the window has closed.
+    pub fn commit(
+        env: Env,
+        owner: Address,
+        hash: BytesN<32>,
+        token: Option<Address>,
+        stake: i128,
+        duration: u64,
+    ) -> u32 {
         owner.require_auth();
 
+        if stake < 0 {
+            panic!("Stake cannot be negative");
+        }
+        if duration == 0 {
+            panic!("Duration must be greater than zero");
+        }
+
         // Get the current game ID counter, defaulting to 0
         let mut counter = env.storage().instance().get(&DataKey::GameCounter).unwrap_or(0);
         counter += 1; // Increment for the new game
 
+        // If this is a wagering game, pull the owner's stake into escrow now
+        // and seed the pot with it.
+        if let Some(token_address) = token.clone() {
+            if stake > 0 {
+                let token_client = token::Client::new(&env, &token_address);
+                token_client.transfer(&owner, &env.current_contract_address(), &stake);
+            }
+            env.storage().persistent().set(&DataKey::Pot(counter), &stake);
+        }
+
         // Create the new game struct
         let new_game = Game {
             owner: owner.clone(),
             commit_hash: hash,
-            reveal_time: env.ledger().timestamp() + 86400, // 24-hour reveal window
-            statements: Vec::new(&env), // Empty for now
-            lie_index: 0,               // Empty for now
-            revealed: false,            // Empty for now
+            reveal_time: env.ledger().timestamp() + duration, // Caller-chosen guessing window
+            statements: Vec::new(&env),  // Empty for now
+            lie_indices: Vec::new(&env), // Empty for now
+            revealed: false,             // Empty for now
+            token,
+            stake,
+            forfeited: false,
+            settled: false,
         };
 
         // Save the new game
         env.storage().persistent().set(&DataKey::Games(counter), &new_game);
         // Save the updated counter
         env.storage().instance().set(&DataKey::GameCounter, &counter);
+        // Every game starts with an empty guesser list
+        env.storage().persistent().set(&DataKey::Guessers(counter), &Vec::<Address>::new(&env));
+
+        // Record this creator's stats and make sure they're in the player registry
+        let mut stats = Self::get_player_stats(env.clone(), owner.clone());
+        stats.games_created += 1;
+        env.storage().persistent().set(&DataKey::PlayerStats(owner.clone()), &stats);
+        Self::remember_player(&env, &owner);
 
         // Log an event
         env.events().publish((symbol_short!("COMMIT"), owner, counter), hash);
@@ -70,31 +172,67 @@ impl TruthsGameContract {
         counter
     }
 
-    /// Allows any user to log a guess for a specific game.
-    pub fn guess(env: Env, guesser: Address, game_id: u32, guessed_index: u32) {
+    /// Allows any user to log their guess for which statements are lies on
+    /// a specific game. `guessed_indices` can name any number of positions;
+    /// a guesser is only right if their set matches the revealed lies
+    /// exactly (see `score_guess`).
+    /// In a wagering game, the guesser must deposit the game's `stake` into
+    /// the pot at the same time as they guess.
+    pub fn guess(env: Env, guesser: Address, game_id: u32, guessed_indices: Vec<u32>) {
         guesser.require_auth();
 
         // Check that the game exists
-        if !env.storage().persistent().has(&DataKey::Games(game_id)) {
-            panic!("Game does not exist");
+        let game = Self::get_game(env.clone(), game_id);
+
+        // The guessing window closes at reveal_time
+        if env.ledger().timestamp() >= game.reveal_time {
+            panic!("Guessing window has closed");
         }
 
         // Store the guess. This will overwrite any previous guess.
         env.storage().persistent().set(
             &DataKey::Guesses(game_id, guesser.clone()),
-            &guessed_index
+            &guessed_indices
         );
 
-        env.events().publish((symbol_short!("GUESS"), guesser, game_id), guessed_index);
+        // Track first-time guessers so `settle` can walk them all later
+        let mut guessers: Vec<Address> = env.storage().persistent()
+            .get(&DataKey::Guessers(game_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        if !guessers.contains(&guesser) {
+            guessers.push_back(guesser.clone());
+            env.storage().persistent().set(&DataKey::Guessers(game_id), &guessers);
+        }
+
+        // Deposit this guesser's stake into the pot, if the game is wagered
+        if let Some(token_address) = game.token {
+            if game.stake > 0 {
+                let token_client = token::Client::new(&env, &token_address);
+                token_client.transfer(&guesser, &env.current_contract_address(), &game.stake);
+
+                let pot: i128 = env.storage().persistent().get(&DataKey::Pot(game_id)).unwrap_or(0);
+                env.storage().persistent().set(&DataKey::Pot(game_id), &(pot + game.stake));
+            }
+        }
+
+        // Record this guesser's stats and make sure they're in the player registry
+        let mut stats = Self::get_player_stats(env.clone(), guesser.clone());
+        stats.guesses_made += 1;
+        env.storage().persistent().set(&DataKey::PlayerStats(guesser.clone()), &stats);
+        Self::remember_player(&env, &guesser);
+
+        env.events().publish((symbol_short!("GUESS"), guesser, game_id), guessed_indices);
     }
 
-    /// Reveals the truths and lie. This verifies the original commitment.
+    /// Reveals the statements and which of them are lies. This verifies the
+    /// original commitment. `lie_indices` may contain one or more positions,
+    /// each of which must fall within `statements`.
     pub fn reveal(
         env: Env,
         owner: Address,
         game_id: u32,
-        statements: Vec<String>, // The 3 statements
-        lie_index: u32,          // The index of the lie
+        statements: Vec<String>, // The statements
+        lie_indices: Vec<u32>,   // The indices of the lies
         salt: String             // The secret password
     ) {
         owner.require_auth();
@@ -110,15 +248,39 @@ impl TruthsGameContract {
         if game.revealed {
             panic!("Game already revealed");
         }
+        // Check that it hasn't been forfeited for missing the deadline
+        if game.forfeited {
+            panic!("Game was forfeited");
+        }
+        // Can't reveal early: the guessing window must be fully closed first,
+        // so the owner can't peek at guesses and reveal before it's fair.
+        if env.ledger().timestamp() < game.reveal_time {
+            panic!("Cannot reveal before the guessing window closes");
+        }
+
+        // Every claimed lie must actually point at one of the statements
+        for idx in lie_indices.iter() {
+            if idx >= statements.len() {
+                panic!("Lie index out of bounds");
+            }
+        }
+
+        // Canonicalize the lie set so the hash doesn't depend on the order
+        // the owner happened to list them in
+        let sorted_lies = Self::sorted_u32(&env, &lie_indices);
 
         // --- This is the core of the Commit-Reveal ---
         // We re-create the hash *inside the contract*
-        // 1. Convert statements, index, and salt to bytes
+        // 1. Convert the statement count, each statement, the sorted lies,
+        // and the salt to bytes, in a fixed canonical order
         let mut bytes_to_hash = bytes::BytesMut::new();
+        bytes_to_hash.extend_from_slice(&statements.len().to_be_bytes());
         for s in statements.iter() {
             bytes_to_hash.extend_from_slice(&s.to_bytes());
         }
-        bytes_to_hash.extend_from_slice(&lie_index.to_be_bytes());
+        for idx in sorted_lies.iter() {
+            bytes_to_hash.extend_from_slice(&idx.to_be_bytes());
+        }
         bytes_to_hash.extend_from_slice(&salt.to_bytes());
 
         // 2. Hash the bytes
@@ -132,13 +294,248 @@ impl TruthsGameContract {
         // --- Success! ---
         // The hashes match. We update the game state to "revealed".
         game.statements = statements.clone();
-        game.lie_index = lie_index;
+        game.lie_indices = sorted_lies;
         game.revealed = true;
 
         // Save the updated game
         env.storage().persistent().set(&DataKey::Games(game_id), &game);
 
-        env.events().publish((symbol_short!("REVEAL"), owner, game_id), lie_index);
+        // One more game has been played out to the end
+        let mut global = Self::get_global_stats(env.clone());
+        global.games_revealed += 1;
+
+        // Credit every correct guesser's stats right here, since wagering
+        // is optional and `settle` (which used to do this) requires a
+        // wager and may never be called for a free game.
+        let winners = Self::find_winners(&env, game_id, &game.lie_indices);
+        for winner in winners.iter() {
+            let mut stats = Self::get_player_stats(env.clone(), winner.clone());
+            stats.correct_guesses += 1;
+            env.storage().persistent().set(&DataKey::PlayerStats(winner.clone()), &stats);
+            global.correct_guesses += 1;
+        }
+        env.storage().instance().set(&DataKey::GlobalStats, &global);
+
+        // Same reasoning applies to this game's tournament scoreboard, if
+        // it's attached to one: `settle` requires a wager, so crediting
+        // scores there would leave free-to-play games permanently stuck at
+        // zero. This also means the score is in by the time `reveal`
+        // returns, instead of waiting on a `settle` that may never come.
+        let tournament_id: Option<u32> = env.storage().persistent().get(&DataKey::GameTournament(game_id));
+        if let Some(tournament_id) = tournament_id {
+            let mut tournament = Self::get_tournament(env.clone(), tournament_id);
+            if tournament.status == TournamentStatus::Open {
+                for winner in winners.iter() {
+                    let current = tournament.scores.get(winner.clone()).unwrap_or(0);
+                    tournament.scores.set(winner.clone(), current + 1);
+                }
+                env.storage().persistent().set(&DataKey::Tournaments(tournament_id), &tournament);
+            }
+        }
+
+        env.events().publish((symbol_short!("REVEAL"), owner, game_id), game.lie_indices.clone());
+    }
+
+    /// Splits a wagering game's pot among the guessers who got the lie
+    /// right. Can only be called after `reveal`. If nobody guessed
+    /// correctly, the owner gets the whole pot, which includes their stake.
+    pub fn settle(env: Env, game_id: u32) {
+        let mut game = Self::get_game(env.clone(), game_id);
+
+        if !game.revealed {
+            panic!("Game has not been revealed yet");
+        }
+        if game.settled {
+            panic!("Game has already been settled");
+        }
+
+        let token_address = match game.token.clone() {
+            Some(t) => t,
+            None => panic!("Game has no wager to settle"),
+        };
+
+        // Mark the game settled up front so this can't be replayed to pay
+        // out the pot again.
+        game.settled = true;
+        env.storage().persistent().set(&DataKey::Games(game_id), &game);
+
+        let pot: i128 = env.storage().persistent().get(&DataKey::Pot(game_id)).unwrap_or(0);
+
+        // `reveal` already credited these same winners' PlayerStats,
+        // GlobalStats, and (if attached) tournament score; here we only
+        // need the winner set again to pay out the pot.
+        let winners = Self::find_winners(&env, game_id, &game.lie_indices);
+
+        let token_client = token::Client::new(&env, &token_address);
+
+        if winners.is_empty() {
+            // Nobody guessed right: the owner keeps their stake and wins the pot
+            if pot > 0 {
+                token_client.transfer(&env.current_contract_address(), &game.owner, &pot);
+            }
+        } else {
+            // Split the pot equally among the winners
+            let share = pot / (winners.len() as i128);
+            for winner in winners.iter() {
+                if share > 0 {
+                    token_client.transfer(&env.current_contract_address(), &winner, &share);
+                }
+            }
+        }
+
+        // The pot's record is cleared either way; any remainder of the
+        // equal split stays in the contract.
+        env.storage().persistent().set(&DataKey::Pot(game_id), &0i128);
+
+        env.events().publish((symbol_short!("SETTLE"), game_id), winners);
+    }
+
+    /// Callable by anyone once `reveal_time` has passed without the owner
+    /// revealing. Marks the game abandoned and refunds every guesser their
+    /// stake. The owner's own stake (the penalty for missing the deadline)
+    /// is then split evenly among the guessers as a bonus, since a
+    /// forfeited game can never reach `reveal`/`settle` to pay it out any
+    /// other way; if nobody guessed, there's no one to penalize them in
+    /// favor of, so it's simply returned to the owner.
+    pub fn forfeit(env: Env, game_id: u32) {
+        let mut game = Self::get_game(env.clone(), game_id);
+
+        if game.revealed {
+            panic!("Game was already revealed");
+        }
+        if game.forfeited {
+            panic!("Game was already forfeited");
+        }
+        if env.ledger().timestamp() < game.reveal_time {
+            panic!("Reveal window has not closed yet");
+        }
+
+        game.forfeited = true;
+        env.storage().persistent().set(&DataKey::Games(game_id), &game);
+
+        if let Some(token_address) = game.token {
+            if game.stake > 0 {
+                let guessers: Vec<Address> = env.storage().persistent()
+                    .get(&DataKey::Guessers(game_id))
+                    .unwrap_or_else(|| Vec::new(&env));
+                let token_client = token::Client::new(&env, &token_address);
+                for guesser in guessers.iter() {
+                    token_client.transfer(&env.current_contract_address(), &guesser, &game.stake);
+                }
+
+                let pot: i128 = env.storage().persistent().get(&DataKey::Pot(game_id)).unwrap_or(0);
+                let refunded = game.stake * (guessers.len() as i128);
+                let penalty = pot - refunded; // What's left: the owner's own stake
+
+                if !guessers.is_empty() {
+                    let bonus = penalty / (guessers.len() as i128);
+                    for guesser in guessers.iter() {
+                        if bonus > 0 {
+                            token_client.transfer(&env.current_contract_address(), &guesser, &bonus);
+                        }
+                    }
+                } else if penalty > 0 {
+                    token_client.transfer(&env.current_contract_address(), &game.owner, &penalty);
+                }
+
+                // The pot's record is cleared either way; any remainder of the
+                // even bonus split stays in the contract.
+                env.storage().persistent().set(&DataKey::Pot(game_id), &0i128);
+            }
+        }
+
+        env.events().publish((symbol_short!("FORFEIT"), game_id), game.owner);
+    }
+
+    /// Starts a new tournament. The organizer attaches games to it with
+    /// `attach_game` and players join with `join_tournament`.
+    pub fn create_tournament(env: Env, organizer: Address) -> u32 {
+        organizer.require_auth();
+
+        let mut counter = env.storage().instance().get(&DataKey::TournamentCounter).unwrap_or(0);
+        counter += 1;
+
+        let tournament = Tournament {
+            organizer: organizer.clone(),
+            game_ids: Vec::new(&env),
+            players: Vec::new(&env),
+            scores: Map::new(&env),
+            status: TournamentStatus::Open,
+        };
+
+        env.storage().persistent().set(&DataKey::Tournaments(counter), &tournament);
+        env.storage().instance().set(&DataKey::TournamentCounter, &counter);
+
+        env.events().publish((symbol_short!("TCREATE"), organizer), counter);
+
+        counter
+    }
+
+    /// Lets a player join an open tournament so their scores get tracked.
+    pub fn join_tournament(env: Env, tournament_id: u32, player: Address) {
+        player.require_auth();
+
+        let mut tournament = Self::get_tournament(env.clone(), tournament_id);
+        if tournament.status != TournamentStatus::Open {
+            panic!("Tournament is no longer open");
+        }
+
+        if !tournament.players.contains(&player) {
+            tournament.players.push_back(player.clone());
+            tournament.scores.set(player.clone(), 0);
+            env.storage().persistent().set(&DataKey::Tournaments(tournament_id), &tournament);
+        }
+    }
+
+    /// Attaches an existing game to a tournament. Only the tournament's
+    /// organizer can do this, and only while the tournament is open, for a
+    /// game they own that isn't already attached elsewhere. Once attached,
+    /// `reveal`-ing that game feeds its winners' scores here.
+    pub fn attach_game(env: Env, organizer: Address, tournament_id: u32, game_id: u32) {
+        organizer.require_auth();
+
+        let mut tournament = Self::get_tournament(env.clone(), tournament_id);
+        if tournament.organizer != organizer {
+            panic!("Only the organizer can attach games");
+        }
+        if tournament.status != TournamentStatus::Open {
+            panic!("Tournament is no longer open");
+        }
+
+        // Only the game's own owner can decide which tournament it counts
+        // towards, and only once: re-attaching would silently move credit
+        // for its outcome from one tournament to another after the fact.
+        let game = Self::get_game(env.clone(), game_id);
+        if game.owner != organizer {
+            panic!("Only the game's owner can attach it to a tournament");
+        }
+        if env.storage().persistent().has(&DataKey::GameTournament(game_id)) {
+            panic!("Game is already attached to a tournament");
+        }
+
+        tournament.game_ids.push_back(game_id);
+        env.storage().persistent().set(&DataKey::Tournaments(tournament_id), &tournament);
+        env.storage().persistent().set(&DataKey::GameTournament(game_id), &tournament_id);
+    }
+
+    /// Closes out a tournament and ranks its players by accumulated
+    /// correct guesses, best first. Only the organizer can do this.
+    pub fn finalize_tournament(env: Env, organizer: Address, tournament_id: u32) {
+        organizer.require_auth();
+
+        let mut tournament = Self::get_tournament(env.clone(), tournament_id);
+        if tournament.organizer != organizer {
+            panic!("Only the organizer can finalize");
+        }
+        if tournament.status == TournamentStatus::Finalized {
+            panic!("Tournament is already finalized");
+        }
+
+        tournament.status = TournamentStatus::Finalized;
+        env.storage().persistent().set(&DataKey::Tournaments(tournament_id), &tournament);
+
+        let standings = Self::get_tournament_standings(env.clone(), tournament_id);
+        env.events().publish((symbol_short!("TOURNEY"), tournament_id), standings);
     }
 
     // --- Read-Only Functions ---
@@ -149,8 +546,8 @@ impl TruthsGameContract {
             .unwrap_or_else(|| panic!("Game not found"))
     }
 
-    /// Gets a specific user's guess for a game
-    pub fn get_guess(env: Env, game_id: u32, guesser: Address) -> Option<u32> {
+    /// Gets a specific user's guessed lie positions for a game
+    pub fn get_guess(env: Env, game_id: u32, guesser: Address) -> Option<Vec<u32>> {
         env.storage().persistent().get(&DataKey::Guesses(game_id, guesser))
     }
 
@@ -158,4 +555,463 @@ impl TruthsGameContract {
     pub fn get_game_count(env: Env) -> u32 {
         env.storage().instance().get(&DataKey::GameCounter).unwrap_or(0)
     }
+
+    /// Gets a player's lifetime stats, or all-zero stats if they've never
+    /// created or guessed on a game.
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        env.storage().persistent().get(&DataKey::PlayerStats(player)).unwrap_or(PlayerStats {
+            games_created: 0,
+            guesses_made: 0,
+            correct_guesses: 0,
+        })
+    }
+
+    /// Gets the contract-wide totals across every game ever revealed.
+    pub fn get_global_stats(env: Env) -> GlobalStats {
+        env.storage().instance().get(&DataKey::GlobalStats).unwrap_or(GlobalStats {
+            games_revealed: 0,
+            correct_guesses: 0,
+        })
+    }
+
+    /// Ranks every known player by correct-guess ratio and returns the
+    /// top `limit` addresses, best first.
+    pub fn get_leaderboard(env: Env, limit: u32) -> Vec<Address> {
+        let players: Vec<Address> = env.storage().instance()
+            .get(&DataKey::Players)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut ranked: Vec<Address> = Vec::new(&env);
+        let slots = if limit < players.len() { limit } else { players.len() };
+
+        for _ in 0..slots {
+            let mut best: Option<Address> = None;
+            let mut best_correct: u32 = 0;
+            let mut best_guesses: u32 = 0;
+
+            for player in players.iter() {
+                if ranked.contains(&player) {
+                    continue;
+                }
+                let stats = Self::get_player_stats(env.clone(), player.clone());
+                // Compare ratios via cross-multiplication so we never need
+                // floating point: correct/guesses > best_correct/best_guesses
+                let is_better = (stats.correct_guesses as u64) * (best_guesses.max(1) as u64)
+                    > (best_correct as u64) * (stats.guesses_made.max(1) as u64);
+                if best.is_none() || is_better {
+                    best = Some(player.clone());
+                    best_correct = stats.correct_guesses;
+                    best_guesses = stats.guesses_made;
+                }
+            }
+
+            match best {
+                Some(player) => ranked.push_back(player),
+                None => break,
+            }
+        }
+
+        ranked
+    }
+
+    /// Gets the details for a single tournament
+    pub fn get_tournament(env: Env, tournament_id: u32) -> Tournament {
+        env.storage().persistent().get(&DataKey::Tournaments(tournament_id))
+            .unwrap_or_else(|| panic!("Tournament not found"))
+    }
+
+    /// Ranks a tournament's joined players by accumulated correct guesses,
+    /// best first. Works before or after `finalize_tournament` is called.
+    pub fn get_tournament_standings(env: Env, tournament_id: u32) -> Vec<Address> {
+        let tournament = Self::get_tournament(env.clone(), tournament_id);
+
+        let mut standings: Vec<Address> = Vec::new(&env);
+        for _ in 0..tournament.players.len() {
+            let mut best: Option<Address> = None;
+            let mut best_score: u32 = 0;
+
+            for player in tournament.players.iter() {
+                if standings.contains(&player) {
+                    continue;
+                }
+                let score = tournament.scores.get(player.clone()).unwrap_or(0);
+                if best.is_none() || score > best_score {
+                    best = Some(player.clone());
+                    best_score = score;
+                }
+            }
+
+            match best {
+                Some(player) => standings.push_back(player),
+                None => break,
+            }
+        }
+
+        standings
+    }
+
+    /// Finds every guesser on a game whose (de-duplicated) guess names
+    /// exactly the revealed lies, no more and no fewer. Used by both
+    /// `reveal` (to credit stats) and `settle` (to pay out the pot).
+    fn find_winners(env: &Env, game_id: u32, lie_indices: &Vec<u32>) -> Vec<Address> {
+        let guessers: Vec<Address> = env.storage().persistent()
+            .get(&DataKey::Guessers(game_id))
+            .unwrap_or_else(|| Vec::new(env));
+
+        let mut winners: Vec<Address> = Vec::new(env);
+        for guesser in guessers.iter() {
+            let guessed: Option<Vec<u32>> = env.storage().persistent()
+                .get(&DataKey::Guesses(game_id, guesser.clone()));
+            if let Some(guessed) = guessed {
+                let guessed = Self::dedup_sorted_u32(env, &guessed);
+                if Self::score_guess(env, &guessed, lie_indices) == lie_indices.len()
+                    && guessed.len() == lie_indices.len()
+                {
+                    winners.push_back(guesser.clone());
+                }
+            }
+        }
+        winners
+    }
+
+    /// Counts how many of `guessed` are actually in `lies`. Combined with a
+    /// length check, an overlap equal to both lengths means an exact match.
+    fn score_guess(_env: &Env, guessed: &Vec<u32>, lies: &Vec<u32>) -> u32 {
+        let mut matches = 0;
+        for g in guessed.iter() {
+            if lies.contains(&g) {
+                matches += 1;
+            }
+        }
+        matches
+    }
+
+    /// Sorts a `Vec<u32>` ascending with a plain insertion sort. Lie sets
+    /// are small (one per statement at most) so this stays cheap.
+    fn sorted_u32(env: &Env, values: &Vec<u32>) -> Vec<u32> {
+        let mut sorted: Vec<u32> = Vec::new(env);
+        for value in values.iter() {
+            let mut insert_at = sorted.len();
+            for (i, existing) in sorted.iter().enumerate() {
+                if value < existing {
+                    insert_at = i as u32;
+                    break;
+                }
+            }
+            sorted.insert(insert_at, value);
+        }
+        sorted
+    }
+
+    /// Sorts a `Vec<u32>` and drops repeated entries, so a guess that
+    /// names the same index more than once can't inflate its match count.
+    fn dedup_sorted_u32(env: &Env, values: &Vec<u32>) -> Vec<u32> {
+        let sorted = Self::sorted_u32(env, values);
+        let mut deduped: Vec<u32> = Vec::new(env);
+        for value in sorted.iter() {
+            if deduped.last() != Some(value) {
+                deduped.push_back(value);
+            }
+        }
+        deduped
+    }
+
+    /// Adds an address to the player registry the first time it's seen.
+    fn remember_player(env: &Env, player: &Address) {
+        let mut players: Vec<Address> = env.storage().instance()
+            .get(&DataKey::Players)
+            .unwrap_or_else(|| Vec::new(env));
+        if !players.contains(player) {
+            players.push_back(player.clone());
+            env.storage().instance().set(&DataKey::Players, &players);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::Ledger as _;
+
+    #[test]
+    #[should_panic(expected = "Only the organizer can finalize")]
+    fn finalize_tournament_rejects_a_non_organizer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, TruthsGameContract);
+        let client = TruthsGameContractClient::new(&env, &contract_id);
+
+        let organizer = Address::generate(&env);
+        let intruder = Address::generate(&env);
+        let tournament_id = client.create_tournament(&organizer);
+
+        client.finalize_tournament(&intruder, &tournament_id);
+    }
+
+    #[test]
+    fn find_winners_does_not_require_a_wager() {
+        // `reveal` credits stats straight from `find_winners`, so a free
+        // (non-wagering) game must be able to identify its winners too.
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TruthsGameContract);
+
+        env.as_contract(&contract_id, || {
+            let winner = Address::generate(&env);
+            let loser = Address::generate(&env);
+
+            env.storage().persistent().set(
+                &DataKey::Guessers(1),
+                &Vec::from_array(&env, [winner.clone(), loser.clone()]),
+            );
+            env.storage().persistent().set(&DataKey::Guesses(1, winner.clone()), &Vec::from_array(&env, [2u32]));
+            env.storage().persistent().set(&DataKey::Guesses(1, loser.clone()), &Vec::from_array(&env, [0u32]));
+
+            let lie_indices: Vec<u32> = Vec::from_array(&env, [2]);
+            let winners = TruthsGameContract::find_winners(&env, 1, &lie_indices);
+
+            assert_eq!(winners.len(), 1);
+            assert_eq!(winners.get(0).unwrap(), winner);
+        });
+    }
+
+    #[test]
+    fn duplicate_guess_indices_cannot_fake_an_exact_match() {
+        let env = Env::default();
+        let lie_indices: Vec<u32> = Vec::from_array(&env, [0, 7]);
+
+        // Submitting the one lie they know, twice, used to count as a
+        // match for both lies because `score_guess` didn't dedupe first.
+        let guessed: Vec<u32> = Vec::from_array(&env, [0, 0]);
+        let deduped = TruthsGameContract::dedup_sorted_u32(&env, &guessed);
+
+        assert_eq!(deduped, Vec::from_array(&env, [0]));
+        assert_ne!(deduped.len(), lie_indices.len());
+    }
+
+    #[test]
+    fn dedup_sorted_u32_sorts_and_drops_repeats() {
+        let env = Env::default();
+        let values: Vec<u32> = Vec::from_array(&env, [3, 1, 3, 2, 1]);
+        let deduped = TruthsGameContract::dedup_sorted_u32(&env, &values);
+
+        assert_eq!(deduped, Vec::from_array(&env, [1, 2, 3]));
+    }
+
+    #[test]
+    fn reveal_credits_tournament_score_for_a_free_attached_game() {
+        // `settle` requires a wager, so a free-to-play game attached to a
+        // tournament can only ever have its score credited from `reveal`.
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, TruthsGameContract);
+        let client = TruthsGameContractClient::new(&env, &contract_id);
+
+        let organizer = Address::generate(&env);
+        let winner = Address::generate(&env);
+
+        let tournament_id = client.create_tournament(&organizer);
+
+        let statements = Vec::from_array(&env, [
+            String::from_str(&env, "true one"),
+            String::from_str(&env, "a lie"),
+        ]);
+        let lie_indices: Vec<u32> = Vec::from_array(&env, [1]);
+        let salt = String::from_str(&env, "salt");
+
+        let mut bytes_to_hash = bytes::BytesMut::new();
+        bytes_to_hash.extend_from_slice(&statements.len().to_be_bytes());
+        for s in statements.iter() {
+            bytes_to_hash.extend_from_slice(&s.to_bytes());
+        }
+        for idx in lie_indices.iter() {
+            bytes_to_hash.extend_from_slice(&idx.to_be_bytes());
+        }
+        bytes_to_hash.extend_from_slice(&salt.to_bytes());
+        let hash = env.crypto().sha256(&bytes_to_hash.to_vec().into());
+
+        let game_id = client.commit(&organizer, &hash, &None, &0, &100);
+        client.attach_game(&organizer, &tournament_id, &game_id);
+        client.guess(&winner, &game_id, &lie_indices);
+
+        env.ledger().with_mut(|l| l.timestamp += 100);
+        client.reveal(&organizer, &game_id, &statements, &lie_indices, &salt);
+
+        let tournament = client.get_tournament(&tournament_id);
+        assert_eq!(tournament.scores.get(winner), Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the game's owner can attach it to a tournament")]
+    fn attach_game_rejects_a_non_owner() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, TruthsGameContract);
+        let client = TruthsGameContractClient::new(&env, &contract_id);
+
+        let organizer = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let hash = BytesN::from_array(&env, &[0u8; 32]);
+
+        let tournament_id = client.create_tournament(&organizer);
+        let game_id = client.commit(&owner, &hash, &None, &0, &100);
+
+        client.attach_game(&organizer, &tournament_id, &game_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Game is already attached to a tournament")]
+    fn attach_game_rejects_reattachment() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, TruthsGameContract);
+        let client = TruthsGameContractClient::new(&env, &contract_id);
+
+        let organizer = Address::generate(&env);
+        let hash = BytesN::from_array(&env, &[0u8; 32]);
+
+        let first_tournament = client.create_tournament(&organizer);
+        let second_tournament = client.create_tournament(&organizer);
+        let game_id = client.commit(&organizer, &hash, &None, &0, &100);
+
+        client.attach_game(&organizer, &first_tournament, &game_id);
+        client.attach_game(&organizer, &second_tournament, &game_id);
+    }
+
+    /// Registers a Stellar Asset Contract administered by `admin` and mints
+    /// `amount` to `to`, so wagering tests have real tokens to move.
+    fn setup_token(env: &Env, admin: &Address, to: &Address, amount: i128) -> Address {
+        let token_contract = env.register_stellar_asset_contract_v2(admin.clone());
+        let token_id = token_contract.address();
+        token::StellarAssetClient::new(env, &token_id).mint(to, &amount);
+        token_id
+    }
+
+    #[test]
+    fn wagering_happy_path_commit_guess_reveal_settle_splits_the_pot() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, TruthsGameContract);
+        let client = TruthsGameContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        let stake: i128 = 100;
+
+        let token_id = setup_token(&env, &admin, &owner, stake);
+        token::StellarAssetClient::new(&env, &token_id).mint(&winner, &stake);
+        token::StellarAssetClient::new(&env, &token_id).mint(&loser, &stake);
+        let token_client = token::Client::new(&env, &token_id);
+
+        let statements = Vec::from_array(&env, [
+            String::from_str(&env, "true one"),
+            String::from_str(&env, "a lie"),
+        ]);
+        let lie_indices: Vec<u32> = Vec::from_array(&env, [1]);
+        let salt = String::from_str(&env, "salt");
+
+        let mut bytes_to_hash = bytes::BytesMut::new();
+        bytes_to_hash.extend_from_slice(&statements.len().to_be_bytes());
+        for s in statements.iter() {
+            bytes_to_hash.extend_from_slice(&s.to_bytes());
+        }
+        for idx in lie_indices.iter() {
+            bytes_to_hash.extend_from_slice(&idx.to_be_bytes());
+        }
+        bytes_to_hash.extend_from_slice(&salt.to_bytes());
+        let hash = env.crypto().sha256(&bytes_to_hash.to_vec().into());
+
+        let game_id = client.commit(&owner, &hash, &Some(token_id.clone()), &stake, &100);
+        assert_eq!(token_client.balance(&owner), 0);
+
+        client.guess(&winner, &game_id, &lie_indices);
+        client.guess(&loser, &game_id, &Vec::from_array(&env, [0u32]));
+        assert_eq!(token_client.balance(&winner), 0);
+
+        env.ledger().with_mut(|l| l.timestamp += 100);
+        client.reveal(&owner, &game_id, &statements, &lie_indices, &salt);
+        client.settle(&game_id);
+
+        // Pot is owner's stake + both guessers' stakes, all to the one winner.
+        assert_eq!(token_client.balance(&winner), stake * 3);
+        assert_eq!(token_client.balance(&loser), 0);
+        assert_eq!(token_client.balance(&owner), 0);
+        assert_eq!(client.get_player_stats(&winner).correct_guesses, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Game has already been settled")]
+    fn settle_cannot_be_replayed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, TruthsGameContract);
+        let client = TruthsGameContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let token_id = setup_token(&env, &admin, &owner, 0);
+
+        let statements = Vec::from_array(&env, [
+            String::from_str(&env, "true one"),
+            String::from_str(&env, "a lie"),
+        ]);
+        let lie_indices: Vec<u32> = Vec::from_array(&env, [1]);
+        let salt = String::from_str(&env, "salt");
+
+        let mut bytes_to_hash = bytes::BytesMut::new();
+        bytes_to_hash.extend_from_slice(&statements.len().to_be_bytes());
+        for s in statements.iter() {
+            bytes_to_hash.extend_from_slice(&s.to_bytes());
+        }
+        for idx in lie_indices.iter() {
+            bytes_to_hash.extend_from_slice(&idx.to_be_bytes());
+        }
+        bytes_to_hash.extend_from_slice(&salt.to_bytes());
+        let hash = env.crypto().sha256(&bytes_to_hash.to_vec().into());
+
+        let game_id = client.commit(&owner, &hash, &Some(token_id), &0, &100);
+        env.ledger().with_mut(|l| l.timestamp += 100);
+        client.reveal(&owner, &game_id, &statements, &lie_indices, &salt);
+
+        client.settle(&game_id);
+        client.settle(&game_id);
+    }
+
+    #[test]
+    fn forfeit_splits_the_owners_stake_among_guessers() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, TruthsGameContract);
+        let client = TruthsGameContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let guesser_a = Address::generate(&env);
+        let guesser_b = Address::generate(&env);
+        let stake: i128 = 100;
+
+        let token_id = setup_token(&env, &admin, &owner, stake);
+        token::StellarAssetClient::new(&env, &token_id).mint(&guesser_a, &stake);
+        token::StellarAssetClient::new(&env, &token_id).mint(&guesser_b, &stake);
+        let token_client = token::Client::new(&env, &token_id);
+
+        let hash = BytesN::from_array(&env, &[0u8; 32]);
+        let game_id = client.commit(&owner, &hash, &Some(token_id), &stake, &100);
+
+        client.guess(&guesser_a, &game_id, &Vec::from_array(&env, [0u32]));
+        client.guess(&guesser_b, &game_id, &Vec::from_array(&env, [1u32]));
+        assert_eq!(token_client.balance(&guesser_a), 0);
+        assert_eq!(token_client.balance(&guesser_b), 0);
+
+        env.ledger().with_mut(|l| l.timestamp += 100);
+        client.forfeit(&game_id);
+
+        // Each guesser gets their stake back plus half of the owner's
+        // forfeited stake as a bonus; nothing is left stuck in the pot.
+        assert_eq!(token_client.balance(&guesser_a), stake + stake / 2);
+        assert_eq!(token_client.balance(&guesser_b), stake + stake / 2);
+        assert_eq!(token_client.balance(&owner), 0);
+    }
 }
\ No newline at end of file